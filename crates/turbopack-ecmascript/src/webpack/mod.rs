@@ -1,10 +1,11 @@
 use anyhow::Result;
-use swc_ecma_ast::Lit;
+use swc_ecma_ast::{BinExpr, BinaryOp, Expr, Lit, MemberProp, PropName};
 use turbo_tasks::{primitives::StringVc, ValueToString};
 use turbo_tasks_fs::{FileContentVc, FileSystemPathVc};
 use turbopack_core::{
     asset::{Asset, AssetVc},
     context::AssetContextVc,
+    issue::{Issue, IssueSeverity, IssueSeverityVc},
     reference::{AssetReference, AssetReferenceVc, AssetReferencesVc},
     resolve::{parse::RequestVc, resolve, ResolveResult, ResolveResultVc},
     source_asset::SourceAssetVc,
@@ -61,6 +62,7 @@ impl Asset for ModuleAsset {
 
 #[turbo_tasks::value(shared)]
 pub struct WebpackChunkAssetReference {
+    pub path: FileSystemPathVc,
     #[trace_ignore]
     pub chunk_id: Lit,
     pub runtime: WebpackRuntimeVc,
@@ -72,27 +74,71 @@ impl AssetReference for WebpackChunkAssetReference {
     #[turbo_tasks::function]
     async fn resolve_reference(&self) -> Result<ResolveResultVc> {
         let runtime = self.runtime.await?;
+        let chunk_id = match &self.chunk_id {
+            Lit::Str(str) => str.value.to_string(),
+            Lit::Num(num) => format!("{num}"),
+            _ => todo!(),
+        };
         Ok(match &*runtime {
             WebpackRuntime::Webpack5 {
-                chunk_request_expr: _,
+                chunk_request_expr,
+                chunk_id_param,
                 context_path,
             } => {
-                // TODO determine filename from chunk_request_expr
-                let chunk_id = match &self.chunk_id {
-                    Lit::Str(str) => str.value.to_string(),
-                    Lit::Num(num) => format!("{num}"),
-                    _ => todo!(),
-                };
-                let filename = format!("./chunks/{}.js", chunk_id);
-                let source = SourceAssetVc::new(context_path.join(&filename)).into();
-
-                ResolveResult::Single(
-                    ModuleAssetVc::new(source, self.runtime, self.transforms).into(),
-                    Vec::new(),
-                )
-                .into()
+                match fold_chunk_request_expr(chunk_request_expr, chunk_id_param, &chunk_id) {
+                    Some(filename) => {
+                        let source = SourceAssetVc::new(context_path.join(&filename)).into();
+                        ResolveResult::Single(
+                            ModuleAssetVc::new(source, self.runtime, self.transforms).into(),
+                            Vec::new(),
+                        )
+                        .into()
+                    }
+                    None => {
+                        // The expression references something we can't statically fold, e.g. a
+                        // `[contenthash]` placeholder that webpack only resolves at runtime.
+                        // Guessing a filename here would silently point at a file that likely
+                        // doesn't exist, so surface it as an issue instead.
+                        WebpackResolveIssue {
+                            path: self.path,
+                            request: format!("webpack chunk {}", chunk_id),
+                            runtime: "webpack 5".to_string(),
+                        }
+                        .cell()
+                        .as_issue()
+                        .emit();
+                        ResolveResult::unresolveable().into()
+                    }
+                }
+            }
+            WebpackRuntime::Webpack4 { .. } => {
+                // Webpack 4's default output.chunkFilename is
+                // `[id].[chunkhash].js`, but the hash is only known to the
+                // webpack build itself, so there's nothing we can statically
+                // fold here. Guessing a filename would silently point at a
+                // file that likely doesn't exist, so surface it as an issue
+                // instead, same as the unfoldable webpack 5 case above.
+                WebpackResolveIssue {
+                    path: self.path,
+                    request: format!("webpack chunk {}", chunk_id),
+                    runtime: "webpack 4".to_string(),
+                }
+                .cell()
+                .as_issue()
+                .emit();
+                ResolveResult::unresolveable().into()
+            }
+            WebpackRuntime::None => {
+                WebpackResolveIssue {
+                    path: self.path,
+                    request: format!("webpack chunk {}", chunk_id),
+                    runtime: "none".to_string(),
+                }
+                .cell()
+                .as_issue()
+                .emit();
+                ResolveResult::unresolveable().into()
             }
-            WebpackRuntime::None => ResolveResult::unresolveable().into(),
         })
     }
 
@@ -107,6 +153,140 @@ impl AssetReference for WebpackChunkAssetReference {
     }
 }
 
+/// Statically evaluates the expression captured from
+/// `__webpack_require__.u`, treating it as string concatenation of literal
+/// parts, the chunk-id parameter (named `chunk_id_param`, substituted with
+/// `chunk_id`), and member lookups `OBJECT[chunkIdParam]` into an object
+/// literal mapping chunk ids to their hash. The parameter name is taken from
+/// the actual function signature rather than assumed to be `chunkId`, since
+/// real (usually minified) bundles rename it. Returns `None` when the
+/// expression contains a node that can't be folded at build time (e.g. a
+/// runtime-resolved `[contenthash]`).
+fn fold_chunk_request_expr(expr: &Expr, chunk_id_param: &str, chunk_id: &str) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        Expr::Ident(ident) if &*ident.sym == chunk_id_param => Some(chunk_id.to_string()),
+        Expr::Tpl(tpl) => {
+            let mut result = String::new();
+            let mut exprs = tpl.exprs.iter();
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                result.push_str(&quasi.raw);
+                if i < tpl.exprs.len() {
+                    result.push_str(&fold_chunk_request_expr(
+                        &exprs.next()?,
+                        chunk_id_param,
+                        chunk_id,
+                    )?);
+                }
+            }
+            Some(result)
+        }
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) => Some(format!(
+            "{}{}",
+            fold_chunk_request_expr(left, chunk_id_param, chunk_id)?,
+            fold_chunk_request_expr(right, chunk_id_param, chunk_id)?
+        )),
+        Expr::Paren(paren) => fold_chunk_request_expr(&paren.expr, chunk_id_param, chunk_id),
+        Expr::Member(member) => {
+            let MemberProp::Computed(computed) = &member.prop else {
+                return None;
+            };
+            let key = fold_chunk_request_expr(&computed.expr, chunk_id_param, chunk_id)?;
+            let mut obj_expr = &*member.obj;
+            while let Expr::Paren(paren) = obj_expr {
+                obj_expr = &paren.expr;
+            }
+            let Expr::Object(obj) = obj_expr else {
+                return None;
+            };
+            obj.props.iter().find_map(|prop| {
+                let kv = prop.as_prop()?.as_key_value()?;
+                let prop_key = match &kv.key {
+                    PropName::Str(str) => str.value.to_string(),
+                    PropName::Num(num) => format!("{num}"),
+                    PropName::Ident(ident) => ident.sym.to_string(),
+                    _ => return None,
+                };
+                (prop_key == key)
+                    .then(|| fold_chunk_request_expr(&kv.value, chunk_id_param, chunk_id))
+                    .flatten()
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use swc_common::{FileName, SourceMap};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+    use super::*;
+
+    fn parse_expr(code: &str) -> Expr {
+        let cm: Arc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon, code.to_string());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        *parser.parse_expr().unwrap()
+    }
+
+    #[test]
+    fn folds_chunk_id_identifier() {
+        let expr = parse_expr("chunkId + \".js\"");
+        assert_eq!(
+            fold_chunk_request_expr(&expr, "chunkId", "42"),
+            Some("42.js".to_string())
+        );
+    }
+
+    #[test]
+    fn folds_renamed_chunk_id_identifier() {
+        // Real (usually minified) bundles don't call the parameter `chunkId`.
+        let expr = parse_expr("e + \".js\"");
+        assert_eq!(
+            fold_chunk_request_expr(&expr, "e", "42"),
+            Some("42.js".to_string())
+        );
+    }
+
+    #[test]
+    fn folds_template_literal() {
+        let expr = parse_expr("`${chunkId}.chunk.js`");
+        assert_eq!(
+            fold_chunk_request_expr(&expr, "chunkId", "7"),
+            Some("7.chunk.js".to_string())
+        );
+    }
+
+    #[test]
+    fn folds_object_lookup_by_chunk_id() {
+        let expr = parse_expr(r#"({"1": "a.js", "2": "b.js"})[chunkId]"#);
+        assert_eq!(
+            fold_chunk_request_expr(&expr, "chunkId", "2"),
+            Some("b.js".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_for_unfoldable_expression() {
+        let expr = parse_expr("someRuntimeValue()");
+        assert_eq!(fold_chunk_request_expr(&expr, "chunkId", "1"), None);
+    }
+}
+
 #[turbo_tasks::value(shared)]
 pub struct WebpackEntryAssetReference {
     pub source: AssetVc,
@@ -117,12 +297,24 @@ pub struct WebpackEntryAssetReference {
 #[turbo_tasks::value_impl]
 impl AssetReference for WebpackEntryAssetReference {
     #[turbo_tasks::function]
-    fn resolve_reference(&self) -> ResolveResultVc {
-        ResolveResult::Single(
+    async fn resolve_reference(&self) -> Result<ResolveResultVc> {
+        let runtime = self.runtime.await?;
+        if matches!(&*runtime, WebpackRuntime::None) {
+            WebpackResolveIssue {
+                path: self.source.path(),
+                request: "webpack entry".to_string(),
+                runtime: "none".to_string(),
+            }
+            .cell()
+            .as_issue()
+            .emit();
+            return Ok(ResolveResult::unresolveable().into());
+        }
+        Ok(ResolveResult::Single(
             ModuleAssetVc::new(self.source, self.runtime, self.transforms).into(),
             Vec::new(),
         )
-        .into()
+        .into())
     }
 
     #[turbo_tasks::function]
@@ -157,6 +349,20 @@ impl AssetReference for WebpackRuntimeAssetReference {
             .into());
         }
 
+        let runtime = self.runtime.await?;
+        WebpackResolveIssue {
+            path: self.context.context_path(),
+            request: self.request.to_string().await?.clone(),
+            runtime: match &*runtime {
+                WebpackRuntime::Webpack5 { .. } => "webpack 5".to_string(),
+                WebpackRuntime::Webpack4 { .. } => "webpack 4".to_string(),
+                WebpackRuntime::None => "none".to_string(),
+            },
+        }
+        .cell()
+        .as_issue()
+        .emit();
+
         Ok(ResolveResult::unresolveable().into())
     }
 
@@ -167,4 +373,47 @@ impl AssetReference for WebpackRuntimeAssetReference {
             self.request.to_string().await?,
         )))
     }
+}
+
+/// Reported when a webpack chunk, entry, or runtime reference fails to
+/// resolve, e.g. because the detected runtime is unsupported or the
+/// underlying request can't be found. Without this, a traced webpack bundle
+/// silently comes up with gaps in its module graph.
+#[turbo_tasks::value(shared)]
+pub struct WebpackResolveIssue {
+    pub path: FileSystemPathVc,
+    pub request: String,
+    pub runtime: String,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for WebpackResolveIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> IssueSeverityVc {
+        IssueSeverity::Warning.into()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell(format!("Unable to resolve {}", self.request))
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("webpack".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        StringVc::cell(format!(
+            "Could not resolve {} while tracing the webpack bundle (runtime: {}). The module \
+             graph is missing this asset.",
+            self.request, self.runtime
+        ))
+    }
 }
\ No newline at end of file
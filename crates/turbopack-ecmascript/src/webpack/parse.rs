@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use swc_common::{FileName, SourceMap};
+use swc_ecma_ast::{AssignExpr, AssignTarget, Expr, Ident, MemberExpr, MemberProp, SimpleAssignTarget};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecmascript::visit::{Visit, VisitWith};
+use turbo_tasks_fs::FileSystemPathVc;
+
+#[turbo_tasks::value(shared)]
+pub enum WebpackRuntime {
+    Webpack5 {
+        #[trace_ignore]
+        chunk_request_expr: Expr,
+        chunk_id_param: String,
+        context_path: FileSystemPathVc,
+    },
+    Webpack4 {
+        context_path: FileSystemPathVc,
+    },
+    None,
+}
+
+/// Parses the contents of a file that is suspected to be a webpack runtime
+/// (or a bundle containing one) and determines which webpack major version
+/// produced it, capturing the expression used by `__webpack_require__.u` to
+/// compute a chunk's filename from its id (webpack 5 only; nothing is
+/// captured for webpack 4, whose `[id].[chunkhash].js` chunk filenames
+/// depend on a hash that can't be computed here).
+pub fn parse(code: &str, context_path: FileSystemPathVc) -> WebpackRuntimeVc {
+    let cm: Arc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, code.to_string());
+
+    let lexer = Lexer::new(
+        Syntax::Es(Default::default()),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+    let Ok(program) = parser.parse_program() else {
+        return WebpackRuntime::None.into();
+    };
+
+    let mut visitor = WebpackRuntimeVisitor::default();
+    program.visit_with(&mut visitor);
+
+    if let (Some(chunk_request_expr), Some(chunk_id_param)) =
+        (visitor.chunk_request_expr, visitor.chunk_id_param)
+    {
+        return WebpackRuntime::Webpack5 {
+            chunk_request_expr,
+            chunk_id_param,
+            context_path,
+        }
+        .into();
+    }
+    if visitor.is_webpack4 {
+        return WebpackRuntime::Webpack4 { context_path }.into();
+    }
+    WebpackRuntime::None.into()
+}
+
+/// Looks for the body of `__webpack_require__.u = function(chunkId) { ... }`
+/// (webpack 5) and records the expression that it returns, which is later
+/// evaluated with the concrete chunk id substituted in. Also flags the code
+/// as a webpack 4 runtime when any one of the identifiers `installedChunks`,
+/// `webpackJsonp` or `jsonpScriptSrc` appears; `__webpack_require__.e` is
+/// not checked.
+#[derive(Default)]
+struct WebpackRuntimeVisitor {
+    chunk_request_expr: Option<Expr>,
+    chunk_id_param: Option<String>,
+    is_webpack4: bool,
+}
+
+impl Visit for WebpackRuntimeVisitor {
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        if self.chunk_request_expr.is_none() {
+            if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left {
+                if is_webpack_require_member(member, "u") {
+                    if let Some(func) = assign.right.as_fn_expr() {
+                        if let Some(body) = &func.function.body {
+                            if let Some(stmt) = body.stmts.last() {
+                                if let Some(ret) = stmt.as_return_stmt() {
+                                    if let Some(arg) = &ret.arg {
+                                        // The parameter name is whatever the (usually
+                                        // minified) bundle happens to call it, not
+                                        // necessarily `chunkId` — capture it so the caller
+                                        // can match the right identifier when folding.
+                                        if let Some(param) = func.function.params.first() {
+                                            if let Some(ident) = param.pat.as_ident() {
+                                                self.chunk_id_param =
+                                                    Some(ident.id.sym.to_string());
+                                                self.chunk_request_expr =
+                                                    Some((**arg).clone());
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        assign.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if matches!(&*ident.sym, "installedChunks" | "webpackJsonp" | "jsonpScriptSrc") {
+            self.is_webpack4 = true;
+        }
+    }
+}
+
+fn is_webpack_require_member(member: &MemberExpr, prop: &str) -> bool {
+    if let MemberProp::Ident(ident) = &member.prop {
+        if &*ident.sym == prop {
+            if let Expr::Ident(obj) = &*member.obj {
+                return &*obj.sym == "__webpack_require__";
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{FileName, SourceMap};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+    use super::*;
+
+    fn visit(code: &str) -> WebpackRuntimeVisitor {
+        let cm: Arc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon, code.to_string());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let program = parser.parse_program().unwrap();
+        let mut visitor = WebpackRuntimeVisitor::default();
+        program.visit_with(&mut visitor);
+        visitor
+    }
+
+    #[test]
+    fn detects_webpack4_via_installed_chunks() {
+        assert!(visit("var installedChunks = {};").is_webpack4);
+    }
+
+    #[test]
+    fn detects_webpack4_via_webpack_jsonp() {
+        assert!(visit("(window.webpackJsonp = window.webpackJsonp || []).push([]);").is_webpack4);
+    }
+
+    #[test]
+    fn detects_webpack4_via_jsonp_script_src() {
+        assert!(visit("function jsonpScriptSrc(chunkId) { return chunkId; }").is_webpack4);
+    }
+
+    #[test]
+    fn does_not_detect_webpack4_for_unrelated_code() {
+        assert!(!visit("var somethingElse = {};").is_webpack4);
+    }
+}
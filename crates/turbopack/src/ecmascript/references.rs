@@ -9,10 +9,15 @@ use crate::{
     asset::AssetVc,
     ecmascript::{utils::js_value_to_pattern, ModuleAssetType},
     errors,
+    issue::{Issue, IssueSeverity, IssueSeverityVc},
+    parse::EcmascriptInputTransformsVc,
     reference::{AssetReference, AssetReferenceVc},
     resolve::{
-        find_context_file, parse::RequestVc, pattern::PatternVc, resolve, resolve_options,
-        resolve_raw, FindContextFileResult, ResolveResult, ResolveResultVc,
+        find_context_file,
+        parse::RequestVc,
+        pattern::{Pattern, PatternVc},
+        resolve, resolve_options, resolve_raw, FindContextFileResult, ResolveResult,
+        ResolveResultVc,
     },
     source_asset::SourceAssetVc,
     target::CompileTarget,
@@ -21,7 +26,7 @@ use anyhow::Result;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     future::Future,
     pin::Pin,
     sync::{Arc, Mutex},
@@ -33,12 +38,19 @@ use swc_common::{
 };
 use swc_ecmascript::{
     ast::{
-        CallExpr, Callee, ComputedPropName, ExportAll, Expr, ExprOrSpread, ImportDecl,
-        ImportSpecifier, Lit, MemberProp, ModuleExportName, NamedExport, VarDeclarator,
+        BinExpr, BinaryOp, CallExpr, Callee, ComputedPropName, Decl, ExportAll, ExportSpecifier,
+        Expr, ExprOrSpread, Ident, ImportDecl, ImportSpecifier, Lit, MemberProp, MetaPropKind,
+        ModuleDecl, ModuleExportName, ModuleItem, NamedExport, NewExpr, Program, PropName,
+        VarDeclarator,
     },
     visit::{self, Visit, VisitWith},
 };
-use turbo_tasks::{util::try_join_all, Value, Vc};
+use turbo_tasks::{
+    primitives::StringVc,
+    trace::{TraceRawVcs, TraceRawVcsContext},
+    util::try_join_all,
+    Value, Vc,
+};
 use turbo_tasks_fs::FileSystemPathVc;
 
 use super::{
@@ -55,12 +67,86 @@ use super::{
     },
 };
 
+/// A reference-counted, immutable string. Cloning an `RcStr` shares its
+/// allocation instead of copying the string. Strings are not interned:
+/// each conversion from a `String` or `&str` allocates a new `RcStr`, even
+/// when an identical string was converted before.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct RcStr(Arc<str>);
+
+impl std::ops::Deref for RcStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RcStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl From<String> for RcStr {
+    fn from(value: String) -> Self {
+        RcStr(value.into())
+    }
+}
+
+impl From<&str> for RcStr {
+    fn from(value: &str) -> Self {
+        RcStr(value.into())
+    }
+}
+
+impl TraceRawVcs for RcStr {
+    fn trace_raw_vcs(&self, _context: &mut TraceRawVcsContext) {
+        // Contains no Vcs to trace.
+    }
+}
+
+/// Options that customize how [`module_references`] analyzes a module.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EcmascriptOptions {
+    /// When a `require`/`import`/`require.resolve` pattern has no constant
+    /// parts (e.g. `require(\`./${dynamic}\`)`), the default behavior is to
+    /// reference every file the glob could match so the bundler pulls in the
+    /// whole directory. Enabling this suppresses that directory-wide
+    /// reference entirely (keeping the existing lint-level warning and adding
+    /// a [`DynamicExpressionReference`] that resolves to nothing and reports
+    /// a [`DynamicRequestIgnoredIssue`]), trading "bundle everything
+    /// reachable" for a smaller graph. There is no
+    /// code generation step in this module that replaces the call itself, so
+    /// at runtime it still executes and resolves however the unmodified
+    /// source says it does (typically `undefined` or a thrown error from the
+    /// underlying `require`/`import`).
+    pub ignore_dynamic_requests: bool,
+}
+
+/// Selects a single named export (and the top-level statements needed to
+/// produce it) out of a module. When analysis is scoped to a part, a
+/// fragment that only imports `{ a }` doesn't drag in references created by
+/// unrelated exports, which is what makes per-export tree-shaking possible.
+#[turbo_tasks::value(shared)]
+#[derive(Hash, Clone, Debug, PartialEq, Eq)]
+pub enum ModulePart {
+    Export(RcStr),
+}
+
 #[turbo_tasks::function]
 pub async fn module_references(
     source: AssetVc,
     ty: Value<ModuleAssetType>,
     target: Value<CompileTarget>,
+    options: Value<EcmascriptOptions>,
+    part: Option<ModulePartVc>,
+    transforms: EcmascriptInputTransformsVc,
 ) -> Result<Vc<Vec<AssetReferenceVc>>> {
+    let part = match part {
+        Some(part) => Some(part.await?),
+        None => None,
+    };
     let mut references = Vec::new();
     let path = source.path();
 
@@ -117,20 +203,14 @@ pub async fn module_references(
                                 if let Some(m) = REFERENCE_PATH.captures(text) {
                                     let path = &m[1];
                                     references.push(
-                                        TsReferencePathAssetReferenceVc::new(
-                                            source,
-                                            path.to_string(),
-                                        )
-                                        .into(),
+                                        TsReferencePathAssetReferenceVc::new(source, path.into())
+                                            .into(),
                                     );
                                 } else if let Some(m) = REFERENCE_TYPES.captures(text) {
                                     let types = &m[1];
                                     references.push(
-                                        TsReferenceTypeAssetReferenceVc::new(
-                                            source,
-                                            types.to_string(),
-                                        )
-                                        .into(),
+                                        TsReferenceTypeAssetReferenceVc::new(source, types.into())
+                                            .into(),
                                     );
                                 }
                             }
@@ -140,6 +220,16 @@ pub async fn module_references(
                 }
             }
 
+            let scoped_program;
+            let program: &Program = match part.as_deref() {
+                Some(part) => {
+                    let reachable = reachable_statement_indices(program, part);
+                    scoped_program = restrict_program_to_statements(program, &reachable);
+                    &scoped_program
+                }
+                None => program,
+            };
+
             let buf = Buffer::new();
             let handler =
                 Handler::with_emitter_writer(Box::new(buf.clone()), Some(source_map.clone()));
@@ -149,8 +239,12 @@ pub async fn module_references(
                         let var_graph = create_graph(&program, eval_context);
 
                         // TODO migrate to effects
-                        let mut visitor =
-                            AssetReferencesVisitor::new(&source, is_typescript, &mut references);
+                        let mut visitor = AssetReferencesVisitor::new(
+                            &source,
+                            is_typescript,
+                            &handler,
+                            &mut references,
+                        );
                         program.visit_with(&mut visitor);
 
                         (
@@ -190,14 +284,57 @@ pub async fn module_references(
                         for chunk in webpack_chunks {
                             references.push(
                                 WebpackChunkAssetReference {
+                                    path: source.path(),
                                     chunk_id: chunk,
                                     runtime: runtime,
+                                    transforms: transforms,
                                 }
                                 .into(),
                             );
                         }
                     }
-                    WebpackRuntime::None => {}
+                    WebpackRuntime::Webpack4 { .. } => {
+                        ignore_effect_span = Some(span);
+                        references.push(
+                            WebpackRuntimeAssetReference {
+                                source: source,
+                                request: request,
+                                runtime: runtime,
+                            }
+                            .into(),
+                        );
+                        if webpack_entry {
+                            references.push(
+                                WebpackEntryAssetReference {
+                                    source: source,
+                                    runtime: runtime,
+                                }
+                                .into(),
+                            );
+                        }
+                        for chunk in webpack_chunks {
+                            references.push(
+                                WebpackChunkAssetReference {
+                                    path: source.path(),
+                                    chunk_id: chunk,
+                                    runtime: runtime,
+                                    transforms: transforms,
+                                }
+                                .into(),
+                            );
+                        }
+                    }
+                    WebpackRuntime::None => {
+                        ignore_effect_span = Some(span);
+                        references.push(
+                            WebpackRuntimeAssetReference {
+                                source: source,
+                                request: request,
+                                runtime: runtime,
+                            }
+                            .into(),
+                        );
+                    }
                 }
             }
 
@@ -214,6 +351,7 @@ pub async fn module_references(
                 args: &'a Vec<JsValue>,
                 link_value: &'a F,
                 is_typescript: bool,
+                ignore_dynamic_requests: bool,
                 references: &'a mut Vec<AssetReferenceVc>,
             ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
                 Box::pin(handle_call(
@@ -225,6 +363,7 @@ pub async fn module_references(
                     args,
                     link_value,
                     is_typescript,
+                    ignore_dynamic_requests,
                     references,
                 ))
             }
@@ -241,6 +380,7 @@ pub async fn module_references(
                 args: &Vec<JsValue>,
                 link_value: &F,
                 is_typescript: bool,
+                ignore_dynamic_requests: bool,
                 references: &mut Vec<AssetReferenceVc>,
             ) -> Result<()> {
                 fn explain_args(args: &Vec<JsValue>) -> (String, String) {
@@ -259,6 +399,7 @@ pub async fn module_references(
                                 args,
                                 link_value,
                                 is_typescript,
+                                ignore_dynamic_requests,
                                 references,
                             )
                             .await?;
@@ -277,7 +418,13 @@ pub async fn module_references(
                                         errors::failed_to_analyse::ecmascript::DYNAMIC_IMPORT
                                             .to_string(),
                                     ),
-                                )
+                                );
+                                if ignore_dynamic_requests {
+                                    references.push(
+                                        DynamicExpressionReferenceVc::new(*source, *span).into(),
+                                    );
+                                    return Ok(());
+                                }
                             }
                             references.push(
                                 EsmAssetReferenceVc::new(
@@ -310,7 +457,13 @@ pub async fn module_references(
                                     DiagnosticId::Lint(
                                         errors::failed_to_analyse::ecmascript::REQUIRE.to_string(),
                                     ),
-                                )
+                                );
+                                if ignore_dynamic_requests {
+                                    references.push(
+                                        DynamicExpressionReferenceVc::new(*source, *span).into(),
+                                    );
+                                    return Ok(());
+                                }
                             }
                             references.push(
                                 CjsAssetReferenceVc::new(
@@ -344,7 +497,13 @@ pub async fn module_references(
                                         errors::failed_to_analyse::ecmascript::REQUIRE_RESOLVE
                                             .to_string(),
                                     ),
-                                )
+                                );
+                                if ignore_dynamic_requests {
+                                    references.push(
+                                        DynamicExpressionReferenceVc::new(*source, *span).into(),
+                                    );
+                                    return Ok(());
+                                }
                             }
                             references.push(
                                 CjsAssetReferenceVc::new(
@@ -504,11 +663,16 @@ pub async fn module_references(
                             ),
                         )
                     }
+                    // `new Worker(...)`/`new URL(..., import.meta.url)` aren't produced as
+                    // `Effect::Call`s by `create_graph` (there is no `NewExpr` → `Effect`
+                    // lowering), so they can't be handled here; see the `visit_new_expr` arm
+                    // on `AssetReferencesVisitor` below instead.
                     _ => {}
                 }
                 Ok(())
             }
 
+            let ignore_dynamic_requests = options.into_value().ignore_dynamic_requests;
             let cache = Mutex::new(LinkCache::new());
             let target = target.into_value();
             let linker = |value| value_visitor(&source, value, target);
@@ -533,6 +697,7 @@ pub async fn module_references(
                             &args,
                             &link_value,
                             is_typescript,
+                            ignore_dynamic_requests,
                             &mut references,
                         )
                         .await?;
@@ -566,6 +731,7 @@ pub async fn module_references(
                             &args,
                             &link_value,
                             is_typescript,
+                            ignore_dynamic_requests,
                             &mut references,
                         )
                         .await?;
@@ -582,6 +748,207 @@ pub async fn module_references(
     Ok(Vc::slot(references))
 }
 
+/// Computes the indices of the top-level module items that are needed to
+/// produce `part`: the item that declares/exports it, plus anything that
+/// item transitively references (computed as a fixed point over free
+/// identifiers, which is a safe over-approximation of true reachability),
+/// plus every statement that introduces no binding at all. Bare expression
+/// statements and side-effect-only imports (`import './polyfill'`) fall into
+/// that last group — they can't be reached by the binding-reachability walk
+/// below since there's no identifier to reference them by, but their side
+/// effects run regardless of which export was requested, so they're kept
+/// unconditionally rather than filtered by reachability.
+fn reachable_statement_indices(program: &Program, part: &ModulePart) -> HashSet<usize> {
+    let ModulePart::Export(export_name) = part;
+    let Program::Module(module) = program else {
+        return HashSet::new();
+    };
+
+    let mut included: HashSet<usize> = module
+        .body
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            statement_declares_export(item, export_name) || statement_bindings(item).is_empty()
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    loop {
+        let mut changed = false;
+        for (i, item) in module.body.iter().enumerate() {
+            if included.contains(&i) {
+                continue;
+            }
+            let bindings = statement_bindings(item);
+            if bindings.is_empty() {
+                continue;
+            }
+            let referenced = included.iter().any(|&j| {
+                let mut collector = IdentCollector::default();
+                module.body[j].visit_with(&mut collector);
+                bindings.iter().any(|binding| collector.idents.contains(binding))
+            });
+            if referenced {
+                included.insert(i);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    included
+}
+
+/// Clones `program`, keeping only the top-level module items whose index is
+/// in `reachable`.
+fn restrict_program_to_statements(program: &Program, reachable: &HashSet<usize>) -> Program {
+    match program {
+        Program::Module(module) => {
+            let mut module = module.clone();
+            module.body = module
+                .body
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| reachable.contains(i))
+                .map(|(_, item)| item.clone())
+                .collect();
+            Program::Module(module)
+        }
+        Program::Script(_) => program.clone(),
+    }
+}
+
+/// The identifiers a top-level item introduces into module scope (import
+/// bindings, and function/class/var declarations, including exported ones).
+fn statement_bindings(item: &ModuleItem) -> Vec<String> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import
+            .specifiers
+            .iter()
+            .map(|specifier| match specifier {
+                ImportSpecifier::Named(named) => named.local.sym.to_string(),
+                ImportSpecifier::Default(default) => default.local.sym.to_string(),
+                ImportSpecifier::Namespace(namespace) => namespace.local.sym.to_string(),
+            })
+            .collect(),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => decl_bindings(&export.decl),
+        ModuleItem::Stmt(stmt) => stmt.as_decl().map(decl_bindings).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+fn decl_bindings(decl: &Decl) -> Vec<String> {
+    match decl {
+        Decl::Fn(func) => vec![func.ident.sym.to_string()],
+        Decl::Class(class) => vec![class.ident.sym.to_string()],
+        Decl::Var(var) => var
+            .decls
+            .iter()
+            .filter_map(|decl| decl.name.as_ident().map(|ident| ident.id.sym.to_string()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether `item` is the declaration site of the named export `export_name`.
+fn statement_declares_export(item: &ModuleItem, export_name: &str) -> bool {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => decl_bindings(&export.decl)
+            .iter()
+            .any(|name| name == export_name),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) => {
+            named.specifiers.iter().any(|specifier| match specifier {
+                ExportSpecifier::Named(named) => {
+                    let exported_name = match named.exported.as_ref().unwrap_or(&named.orig) {
+                        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+                        ModuleExportName::Str(str) => str.value.to_string(),
+                    };
+                    exported_name == export_name
+                }
+                _ => false,
+            })
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(_)) => export_name == "default",
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct IdentCollector {
+    idents: HashSet<String>,
+}
+
+impl Visit for IdentCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.idents.insert(ident.sym.to_string());
+    }
+}
+
+#[cfg(test)]
+mod part_tests {
+    use swc_common::{FileName, SourceMap};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+    use super::*;
+
+    fn parse_module(code: &str) -> Program {
+        let cm: Arc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon, code.to_string());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        parser.parse_program().unwrap()
+    }
+
+    #[test]
+    fn includes_only_the_exports_own_declarations_when_independent() {
+        let program = parse_module(
+            "export const a = 1;\nexport const b = 2;\nconsole.log('side effect');",
+        );
+        let part = ModulePart::Export("a".into());
+        let reachable = reachable_statement_indices(&program, &part);
+        // `b`'s declaration (1) is unreachable from `a` and is dropped, but the
+        // side-effect statement (2) introduces no binding, so it's always kept.
+        assert_eq!(reachable, HashSet::from([0, 2]));
+    }
+
+    #[test]
+    fn keeps_side_effect_only_import_regardless_of_export() {
+        let program = parse_module("import './polyfill';\nexport const a = 1;\nexport const b = 2;");
+        let part = ModulePart::Export("a".into());
+        let reachable = reachable_statement_indices(&program, &part);
+        assert_eq!(reachable, HashSet::from([0, 1]));
+    }
+
+    #[test]
+    fn pulls_in_transitively_referenced_declarations() {
+        let program = parse_module(
+            "function helper() { return 1; }\nexport const a = helper();\nexport const b = 2;",
+        );
+        let part = ModulePart::Export("a".into());
+        let reachable = reachable_statement_indices(&program, &part);
+        assert_eq!(reachable, HashSet::from([0, 1]));
+    }
+
+    #[test]
+    fn restrict_program_to_statements_drops_unreachable_items() {
+        let program = parse_module("export const a = 1;\nexport const b = 2;");
+        let reachable = HashSet::from([0]);
+        let Program::Module(module) = restrict_program_to_statements(&program, &reachable) else {
+            panic!("expected a module");
+        };
+        assert_eq!(module.body.len(), 1);
+    }
+}
+
 async fn as_abs_path(path: FileSystemPathVc) -> Result<JsValue> {
     Ok(format!("/ROOT/{}", path.await?.path.as_str()).into())
 }
@@ -685,13 +1052,16 @@ async fn value_visitor_inner(
 enum StaticExpr {
     String(String),
     FreeVar(Vec<String>),
-    ImportedVar(String, Vec<String>),
+    ImportedVar(RcStr, Vec<String>),
     Unknown,
 }
 
 #[derive(Default)]
 struct StaticAnalyser {
-    imports: HashMap<String, (String, Vec<String>)>,
+    // The module specifier is an `RcStr`, so it's cheap to clone: several
+    // named imports from the same source share it, and every `Expr::Ident`
+    // lookup clones its entry (the `Vec<String>` path is copied in full).
+    imports: HashMap<String, (RcStr, Vec<String>)>,
 }
 
 impl StaticAnalyser {
@@ -742,29 +1112,225 @@ impl StaticAnalyser {
     }
 }
 
+/// Folds an expression into a constant string when it's built entirely out of
+/// string literals, template literals without substitutions, and `+`
+/// concatenation of either. Returns `None` for anything that depends on a
+/// runtime value.
+fn literal_string(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+            Some(tpl.quasis.iter().map(|quasi| quasi.raw.to_string()).collect())
+        }
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) => Some(format!(
+            "{}{}",
+            literal_string(left)?,
+            literal_string(right)?
+        )),
+        Expr::Paren(paren) => literal_string(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Whether `expr` is an object literal containing `eval: true`, i.e. the
+/// `new Worker(code, { eval: true })` form that runs a string of code
+/// directly instead of loading a sibling module.
+fn has_eval_true_option(expr: &Expr) -> bool {
+    let Expr::Object(obj) = expr else {
+        return false;
+    };
+    obj.props.iter().any(|prop| {
+        let Some(kv) = prop.as_prop().and_then(|prop| prop.as_key_value()) else {
+            return false;
+        };
+        let key_is_eval = match &kv.key {
+            PropName::Ident(ident) => &*ident.sym == "eval",
+            PropName::Str(str) => &*str.value == "eval",
+            _ => false,
+        };
+        key_is_eval && matches!(&*kv.value, Expr::Lit(Lit::Bool(b)) if b.value)
+    })
+}
+
+/// Whether `expr` is `import.meta.url`, the standard way to anchor a
+/// `new URL(...)` asset reference to the current module's location.
+fn is_import_meta_url(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Member(member)
+            if matches!(&*member.obj, Expr::MetaProp(meta) if meta.kind == MetaPropKind::ImportMeta)
+                && matches!(&member.prop, MemberProp::Ident(ident) if &*ident.sym == "url")
+    )
+}
+
+/// If `expr` is `new URL(specifier, import.meta.url)` with a literal
+/// `specifier` — the documented way to point a worker at a sibling module —
+/// returns that specifier. This lets `new Worker(new URL(...))` resolve the
+/// worker's entry point instead of treating the `new URL(...)` argument as
+/// an unresolvable dynamic value.
+fn worker_url_specifier(expr: &Expr) -> Option<String> {
+    let Expr::New(new_expr) = expr else {
+        return None;
+    };
+    let Expr::Ident(ident) = &*new_expr.callee else {
+        return None;
+    };
+    if &*ident.sym != "URL" {
+        return None;
+    }
+    let args: Vec<&Expr> = new_expr
+        .args
+        .iter()
+        .flatten()
+        .map(|arg| &*arg.expr)
+        .collect();
+    let (&specifier, &base) = (args.first()?, args.get(1)?);
+    if !is_import_meta_url(base) {
+        return None;
+    }
+    literal_string(specifier)
+}
+
+#[cfg(test)]
+mod static_expr_tests {
+    use swc_common::{FileName, SourceMap};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+    use super::*;
+
+    fn parse_expr(code: &str) -> Expr {
+        let cm: Arc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon, code.to_string());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        *parser.parse_expr().unwrap()
+    }
+
+    #[test]
+    fn literal_string_folds_string_literal() {
+        assert_eq!(
+            literal_string(&parse_expr("\"./worker.js\"")),
+            Some("./worker.js".to_string())
+        );
+    }
+
+    #[test]
+    fn literal_string_folds_template_without_substitutions() {
+        assert_eq!(
+            literal_string(&parse_expr("`./worker.js`")),
+            Some("./worker.js".to_string())
+        );
+    }
+
+    #[test]
+    fn literal_string_folds_concatenation() {
+        assert_eq!(
+            literal_string(&parse_expr("\"./worker\" + \".js\"")),
+            Some("./worker.js".to_string())
+        );
+    }
+
+    #[test]
+    fn literal_string_returns_none_for_runtime_value() {
+        assert_eq!(literal_string(&parse_expr("someRuntimeValue")), None);
+    }
+
+    #[test]
+    fn has_eval_true_option_detects_eval_true() {
+        assert!(has_eval_true_option(&parse_expr("({ eval: true })")));
+    }
+
+    #[test]
+    fn has_eval_true_option_ignores_eval_false() {
+        assert!(!has_eval_true_option(&parse_expr("({ eval: false })")));
+    }
+
+    #[test]
+    fn has_eval_true_option_ignores_unrelated_object() {
+        assert!(!has_eval_true_option(&parse_expr("({ type: \"module\" })")));
+    }
+
+    #[test]
+    fn is_import_meta_url_recognizes_import_meta_url() {
+        assert!(is_import_meta_url(&parse_expr("import.meta.url")));
+    }
+
+    #[test]
+    fn is_import_meta_url_rejects_other_import_meta_properties() {
+        assert!(!is_import_meta_url(&parse_expr("import.meta.env")));
+    }
+
+    #[test]
+    fn is_import_meta_url_rejects_unrelated_member_expression() {
+        assert!(!is_import_meta_url(&parse_expr("someObject.url")));
+    }
+
+    #[test]
+    fn worker_url_specifier_extracts_literal_specifier() {
+        assert_eq!(
+            worker_url_specifier(&parse_expr("new URL(\"./worker.js\", import.meta.url)")),
+            Some("./worker.js".to_string())
+        );
+    }
+
+    #[test]
+    fn worker_url_specifier_returns_none_without_import_meta_url() {
+        assert_eq!(
+            worker_url_specifier(&parse_expr("new URL(\"./worker.js\", someBase)")),
+            None
+        );
+    }
+
+    #[test]
+    fn worker_url_specifier_returns_none_for_non_url_constructor() {
+        assert_eq!(
+            worker_url_specifier(&parse_expr("new Foo(\"./worker.js\", import.meta.url)")),
+            None
+        );
+    }
+}
+
 struct AssetReferencesVisitor<'a> {
     source: &'a AssetVc,
     is_typescript: bool,
+    handler: &'a Handler,
     old_analyser: StaticAnalyser,
     references: &'a mut Vec<AssetReferenceVc>,
     webpack_runtime: Option<(String, Span)>,
     webpack_entry: bool,
     webpack_chunks: Vec<Lit>,
+    // Spans of `new URL(...)` nodes already folded into a `new Worker(new
+    // URL(...))` reference, so the generic `new URL(...)` handling below
+    // doesn't also emit a second, less precise reference for the same node.
+    consumed_worker_urls: HashSet<Span>,
 }
 impl<'a> AssetReferencesVisitor<'a> {
     fn new(
         source: &'a AssetVc,
         is_typescript: bool,
+        handler: &'a Handler,
         references: &'a mut Vec<AssetReferenceVc>,
     ) -> Self {
         Self {
             source,
             is_typescript,
+            handler,
             old_analyser: StaticAnalyser::default(),
             references,
             webpack_runtime: None,
             webpack_entry: false,
             webpack_chunks: Vec::new(),
+            consumed_worker_urls: HashSet::new(),
         }
     }
 }
@@ -797,11 +1363,11 @@ impl<'a> Visit for AssetReferencesVisitor<'a> {
         visit::visit_named_export(self, export);
     }
     fn visit_import_decl(&mut self, import: &ImportDecl) {
-        let src = import.src.value.to_string();
+        let src: RcStr = import.src.value.to_string().into();
         self.references.push(
             EsmAssetReferenceVc::new(
                 *self.source,
-                RequestVc::parse(Value::new(src.clone().into())),
+                RequestVc::parse(Value::new(src.to_string().into())),
                 self.is_typescript,
             )
             .into(),
@@ -905,6 +1471,89 @@ impl<'a> Visit for AssetReferencesVisitor<'a> {
         }
         visit::visit_call_expr(self, call);
     }
+
+    fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+        let callee = match self.old_analyser.evaluate_expr(&new_expr.callee) {
+            StaticExpr::ImportedVar(module, path) => Some((Some(module), path)),
+            StaticExpr::FreeVar(path) => Some((None, path)),
+            _ => None,
+        };
+        let args: Vec<&Expr> = new_expr
+            .args
+            .iter()
+            .flatten()
+            .map(|arg| &*arg.expr)
+            .collect();
+
+        if let Some((module, path)) = &callee {
+            let is_worker = matches!(
+                (module.as_deref(), path.as_slice()),
+                (Some("worker_threads") | Some("node:worker_threads"), [member]) if member == "Worker"
+            );
+            let is_url = module.is_none() && matches!(path.as_slice(), [member] if member == "URL");
+
+            if is_worker
+                && !args.get(1).is_some_and(|options| has_eval_true_option(options))
+            {
+                if let Some(&specifier) = args.first() {
+                    match literal_string(specifier).or_else(|| worker_url_specifier(specifier)) {
+                        Some(request) => {
+                            if let Expr::New(inner) = specifier {
+                                self.consumed_worker_urls.insert(inner.span);
+                            }
+                            let pattern: Pattern = request.into();
+                            self.references.push(
+                                CjsAssetReferenceVc::new(
+                                    *self.source,
+                                    RequestVc::parse(Value::new(pattern)),
+                                    self.is_typescript,
+                                )
+                                .into(),
+                            );
+                        }
+                        None => {
+                            self.handler.span_warn_with_code(
+                                new_expr.span,
+                                "new Worker(...) is very dynamic and can't be resolved \
+                                 statically",
+                                DiagnosticId::Lint(
+                                    errors::failed_to_analyse::ecmascript::WORKER_THREADS
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    }
+                }
+            } else if is_url && !self.consumed_worker_urls.contains(&new_expr.span) {
+                if let (Some(&specifier), Some(&base)) = (args.first(), args.get(1)) {
+                    if is_import_meta_url(base) {
+                        match literal_string(specifier) {
+                            Some(request) => {
+                                let pattern: Pattern = request.into();
+                                self.references.push(
+                                    SourceAssetReferenceVc::new(*self.source, pattern.into())
+                                        .into(),
+                                );
+                            }
+                            None => {
+                                self.handler.span_warn_with_code(
+                                    new_expr.span,
+                                    "new URL(...) is very dynamic and can't be resolved \
+                                     statically",
+                                    DiagnosticId::Lint(
+                                        errors::failed_to_analyse::ecmascript::NEW_URL_IMPORT_META
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        visit::visit_new_expr(self, new_expr);
+    }
 }
 
 #[turbo_tasks::function]
@@ -1047,13 +1696,13 @@ impl AssetReference for CjsAssetReference {
 #[derive(Hash, Debug, PartialEq, Eq)]
 pub struct TsReferencePathAssetReference {
     pub source: AssetVc,
-    pub path: String,
+    pub path: RcStr,
 }
 
 #[turbo_tasks::value_impl]
 impl TsReferencePathAssetReferenceVc {
     #[turbo_tasks::function]
-    pub fn new(source: AssetVc, path: String) -> Self {
+    pub fn new(source: AssetVc, path: RcStr) -> Self {
         Self::slot(TsReferencePathAssetReference { source, path })
     }
 }
@@ -1076,13 +1725,13 @@ impl AssetReference for TsReferencePathAssetReference {
 #[derive(Hash, Debug, PartialEq, Eq)]
 pub struct TsReferenceTypeAssetReference {
     pub source: AssetVc,
-    pub module: String,
+    pub module: RcStr,
 }
 
 #[turbo_tasks::value_impl]
 impl TsReferenceTypeAssetReferenceVc {
     #[turbo_tasks::function]
-    pub fn new(source: AssetVc, module: String) -> Self {
+    pub fn new(source: AssetVc, module: RcStr) -> Self {
         Self::slot(TsReferenceTypeAssetReference { source, module })
     }
 }
@@ -1094,13 +1743,96 @@ impl AssetReference for TsReferenceTypeAssetReference {
         let context = self.source.path().parent();
         let options = typescript_types_resolve_options(context);
         type_resolve(
-            RequestVc::module(self.module.clone(), Value::new("".to_string().into())),
+            RequestVc::module(self.module.to_string(), Value::new("".to_string().into())),
             context,
             options,
         )
     }
 }
 
+/// Marks the location of a `require`/`import`/`require.resolve` call whose
+/// pattern has no constant parts and that [`EcmascriptOptions::
+/// ignore_dynamic_requests`] chose not to turn into a directory-wide
+/// reference. It resolves to nothing, so the bundler's module graph doesn't
+/// grow from this call, and reports a [`DynamicRequestIgnoredIssue`] so the
+/// suppression isn't silent: the original, unmodified call still executes at
+/// runtime, and nothing in this crate rewrites it, so a consumer who enabled
+/// the option sees exactly why this call contributed no assets.
+#[turbo_tasks::value(AssetReference)]
+#[derive(Hash, Debug, PartialEq, Eq)]
+pub struct DynamicExpressionReference {
+    pub source: AssetVc,
+    #[trace_ignore]
+    pub span: Span,
+}
+
+#[turbo_tasks::value_impl]
+impl DynamicExpressionReferenceVc {
+    #[turbo_tasks::function]
+    pub fn new(source: AssetVc, span: Span) -> Self {
+        Self::slot(DynamicExpressionReference { source, span })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl AssetReference for DynamicExpressionReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> ResolveResultVc {
+        DynamicRequestIgnoredIssue {
+            path: self.source.path(),
+        }
+        .cell()
+        .as_issue()
+        .emit();
+
+        ResolveResult::unresolveable().into()
+    }
+}
+
+/// Reported when [`EcmascriptOptions::ignore_dynamic_requests`] suppresses a
+/// dynamic `require`/`import`/`require.resolve` call's directory-wide
+/// reference. Without this, enabling the option silently drops the only
+/// signal that the call exists, leaving no trace of why the module graph
+/// doesn't contain whatever it would have resolved to at runtime.
+#[turbo_tasks::value(shared)]
+pub struct DynamicRequestIgnoredIssue {
+    pub path: FileSystemPathVc,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for DynamicRequestIgnoredIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> IssueSeverityVc {
+        IssueSeverity::Warning.into()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell("Dynamic request ignored".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("ecmascript".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        StringVc::cell(
+            "A require()/import()/require.resolve() call with a fully dynamic argument was not \
+             turned into a directory-wide reference because ignore_dynamic_requests is enabled. \
+             The call still executes unmodified at runtime; this crate does not rewrite it, so \
+             the module graph is missing whatever it resolves to."
+                .to_string(),
+        )
+    }
+}
+
 #[turbo_tasks::value(AssetReference)]
 #[derive(Hash, Debug, PartialEq, Eq)]
 pub struct SourceAssetReference {